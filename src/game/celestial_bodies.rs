@@ -2,6 +2,8 @@ use std::iter::Iterator;
 
 pub mod star;
 pub mod planet;
+pub mod moon;
+pub mod asteroid_belt;
 pub mod solar_system;
 
 mod constants {
@@ -91,15 +93,28 @@ pub trait Orbitable {
 
 pub trait CanOrbit {
     type HostType: Orbitable;
-    
+
+    /// Current distance from the host, in AU. For an elliptical orbit this
+    /// varies between `a*(1-e)` at periapsis and `a*(1+e)` at apoapsis.
     fn get_orbit_radius(&self) -> f32;
     fn get_orbit_period(&self) -> f32;
 
-    /// Returns the position in orbit in radians [0; 2pi], counting from the rightmost point
+    /// Returns the position in orbit in radians [0; 2pi], counting from the rightmost point.
+    /// This is the true anomaly `ν`, measured from periapsis.
     fn get_orbit_position(&self) -> f32;
 
     fn get_angular_speed(&self) -> f32;
 
+    /// Orbital eccentricity `e`, where `0` is a perfect circle and values
+    /// approaching `1` are increasingly elongated ellipses.
+    fn get_eccentricity(&self) -> f32;
+
+    /// Orbital inclination, in radians, relative to the host's reference plane.
+    fn get_inclination(&self) -> f32;
+
+    /// Semi-major axis `a` of the orbit, in AU.
+    fn get_semi_major_axis(&self) -> f32;
+
     fn update_orbit_position(&mut self);
 }
 
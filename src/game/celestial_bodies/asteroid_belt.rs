@@ -0,0 +1,60 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Relative proportions of mineable resources found in an asteroid belt.
+/// The three fractions always sum to `1.0`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ResourceComposition {
+    pub mineral: f32,
+    pub metal: f32,
+    pub ice: f32,
+}
+
+impl ResourceComposition {
+    fn generate(rng: &mut impl Rng) -> Self {
+        let mineral: f32 = rng.gen_range(0.0..1.0);
+        let metal: f32 = rng.gen_range(0.0..1.0);
+        let ice: f32 = rng.gen_range(0.0..1.0);
+        let total = mineral + metal + ice;
+
+        Self {
+            mineral: mineral / total,
+            metal: metal / total,
+            ice: ice / total,
+        }
+    }
+}
+
+/// A belt of asteroids occupying the gap between two adjacent planet orbits,
+/// carrying a mineable resource composition.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AsteroidBelt {
+    inner_radius: f32,
+    outer_radius: f32,
+    composition: ResourceComposition,
+}
+
+impl AsteroidBelt {
+    /// Generates a belt spanning `[inner_radius, outer_radius]` AU, drawing
+    /// its resource composition from `rng` so that generation can be made
+    /// deterministic by seeding it.
+    pub(crate) fn generate_with_rng(inner_radius: f32, outer_radius: f32, rng: &mut impl Rng) -> Self {
+        Self {
+            inner_radius,
+            outer_radius,
+            composition: ResourceComposition::generate(rng),
+        }
+    }
+
+    pub fn get_inner_radius(&self) -> f32 {
+        self.inner_radius
+    }
+
+    pub fn get_outer_radius(&self) -> f32 {
+        self.outer_radius
+    }
+
+    pub fn get_composition(&self) -> ResourceComposition {
+        self.composition
+    }
+}
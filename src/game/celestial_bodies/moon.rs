@@ -0,0 +1,172 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+use rand_distr;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::celestial_bodies::planet::Planet;
+use crate::game::celestial_bodies::{CanOrbit, CelestialBody, CelestialBodyType, Displayable, Orbitable};
+use crate::game::helpers::orbit_dynamics;
+
+/// Simulated time (years) that a single `update_orbit_position` tick advances.
+const ORBIT_TIME_STEP: f32 = 0.01;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Moon {
+    id: Uuid,
+    name: String,
+    mass: f32,
+    radius: f32,
+    semi_major_axis: f32,
+    eccentricity: f32,
+    inclination: f32,
+    orbit_period: f32,
+    angular_speed: f32,
+    orbit_position: f32,
+    current_radius: f32,
+    time_elapsed: f32,
+}
+
+impl Moon {
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn designation(host: &Planet, index: u32) -> String {
+        // Moons are conventionally numbered with Roman numerals in order of
+        // discovery, which for a generated system is the order of generation
+        // (not distance: each moon's orbit radius is drawn independently).
+        const NUMERALS: [&str; 8] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII"];
+        let numeral = NUMERALS.get(index as usize).copied().unwrap_or("?");
+        format!("{} {}", host.get_name(), numeral)
+    }
+
+    /// Generates a moon orbiting `host`, drawing every random quantity from
+    /// `rng` so that generation can be made deterministic by seeding it. The
+    /// orbit radius is kept within `host`'s Hill sphere so the system stays
+    /// dynamically stable.
+    pub(crate) fn generate_with_rng(host: Planet, rng: &mut impl Rng) -> Self {
+        let index = host.get_satellites().len() as u32;
+        let max_orbit_radius = host.get_max_moon_orbit_radius().max(f32::EPSILON);
+        let semi_major_axis = max_orbit_radius * rng.gen_range(0.2_f32..1.0);
+
+        // Moons settle into near-circular orbits much more tightly than
+        // planets do, so the eccentricity distribution is narrower here; the
+        // inclination spread (5 degrees) is wider than the planets' 3 degrees.
+        let eccentricity: f32 = rand_distr::Normal::new(0.0_f32, 0.03_f32)
+            .unwrap()
+            .sample(rng)
+            .abs()
+            .min(0.5);
+
+        let inclination = rand_distr::Normal::new(0.0, 5.0_f32.to_radians())
+            .unwrap()
+            .sample(rng);
+
+        let mass: f32 = rand_distr::LogNormal::new(-11.0, 1.0)
+            .unwrap()
+            .sample(rng);
+        let radius = mass.powf(0.5);
+
+        let orbit_period = orbit_dynamics::calculate_orbital_period(semi_major_axis, host.get_mass());
+        let angular_speed = orbit_dynamics::calculate_angular_speed(orbit_period);
+
+        // At t = 0 every moon starts at periapsis (E = 0, so ν = 0).
+        let current_radius = semi_major_axis * (1.0 - eccentricity);
+
+        let name = Self::designation(&host, index);
+
+        Self {
+            id: Uuid::from_bytes(rng.gen()),
+            name,
+            mass,
+            radius,
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            orbit_period,
+            angular_speed,
+            orbit_position: 0.0,
+            current_radius,
+            time_elapsed: 0.0,
+        }
+    }
+}
+
+impl CelestialBody for Moon {
+    type HostType = Planet;
+
+    fn get_type(&self) -> CelestialBodyType {
+        CelestialBodyType::Moon
+    }
+
+    fn get_mass(&self) -> f32 {
+        self.mass
+    }
+
+    fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    fn generate(host: Planet) -> Self {
+        Self::generate_with_rng(host, &mut rand::thread_rng())
+    }
+}
+
+impl CanOrbit for Moon {
+    type HostType = Planet;
+
+    fn get_orbit_radius(&self) -> f32 {
+        self.current_radius
+    }
+
+    fn get_orbit_period(&self) -> f32 {
+        self.orbit_period
+    }
+
+    fn get_orbit_position(&self) -> f32 {
+        self.orbit_position
+    }
+
+    fn get_angular_speed(&self) -> f32 {
+        self.angular_speed
+    }
+
+    fn get_eccentricity(&self) -> f32 {
+        self.eccentricity
+    }
+
+    fn get_inclination(&self) -> f32 {
+        self.inclination
+    }
+
+    fn get_semi_major_axis(&self) -> f32 {
+        self.semi_major_axis
+    }
+
+    fn update_orbit_position(&mut self) {
+        self.time_elapsed += ORBIT_TIME_STEP;
+
+        let mean_anomaly = orbit_dynamics::calculate_mean_anomaly(self.time_elapsed, self.orbit_period);
+        let eccentric_anomaly = orbit_dynamics::solve_kepler_equation(mean_anomaly, self.eccentricity);
+
+        self.orbit_position = orbit_dynamics::calculate_true_anomaly(eccentric_anomaly, self.eccentricity);
+        self.current_radius =
+            orbit_dynamics::calculate_orbital_radius(self.semi_major_axis, self.eccentricity, eccentric_anomaly);
+    }
+}
+
+impl Displayable for Moon {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec!["Mass".to_string(), format!("{:.6} M\u{2609}", self.mass)],
+            vec!["Radius".to_string(), format!("{:.6} R\u{2609}", self.radius)],
+            vec!["Orbit radius".to_string(), format!("{:.5} AU", self.semi_major_axis)],
+            vec!["Eccentricity".to_string(), format!("{:.3}", self.eccentricity)],
+        ]
+    }
+}
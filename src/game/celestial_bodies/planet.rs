@@ -0,0 +1,318 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+use rand_distr;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::celestial_bodies::moon::Moon;
+use crate::game::celestial_bodies::solar_system::SolarSystem;
+use crate::game::celestial_bodies::{CanOrbit, CelestialBody, CelestialBodyType, Displayable, Orbitable};
+use crate::game::helpers::{astrophysics, orbit_dynamics};
+
+/// Mass (in solar masses, about 1.6 Jupiter masses) above which a
+/// planet is considered a gas giant rather than a terrestrial body.
+const GAS_GIANT_MASS_THRESHOLD: f32 = 0.0015;
+
+/// Simulated time (years) that a single `update_orbit_position` tick advances.
+const ORBIT_TIME_STEP: f32 = 0.01;
+
+/// Fraction of the Hill radius a moon's orbit is allowed to reach, keeping
+/// satellites well clear of the point where the star's gravity would start
+/// to strip them away.
+const MAX_MOON_ORBIT_FRACTION: f32 = 0.5;
+
+/// Chance that a gas giant forms a ring system.
+const RING_PROBABILITY: f64 = 0.4;
+
+/// A planetary ring, modeled as an inner and outer radius around the planet.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct Ring {
+    inner_radius: f32,
+    outer_radius: f32,
+}
+
+impl Ring {
+    pub fn get_inner_radius(&self) -> f32 {
+        self.inner_radius
+    }
+
+    pub fn get_outer_radius(&self) -> f32 {
+        self.outer_radius
+    }
+}
+
+/// Whether a planet's equilibrium temperature falls within the band where
+/// liquid water can persist on its surface.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum HabitabilityClass {
+    TooHot,
+    Habitable,
+    TooCold,
+}
+
+impl HabitabilityClass {
+    /// Classifies a surface temperature (Kelvin) against the liquid-water band.
+    fn from_temperature(surface_temperature: f32) -> Self {
+        if surface_temperature > astrophysics::HABITABLE_MAX_TEMPERATURE {
+            HabitabilityClass::TooHot
+        } else if surface_temperature < astrophysics::HABITABLE_MIN_TEMPERATURE {
+            HabitabilityClass::TooCold
+        } else {
+            HabitabilityClass::Habitable
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Planet {
+    id: Uuid,
+    name: String,
+    mass: f32,
+    radius: f32,
+    semi_major_axis: f32,
+    eccentricity: f32,
+    inclination: f32,
+    orbit_period: f32,
+    angular_speed: f32,
+    orbit_position: f32,
+    current_radius: f32,
+    time_elapsed: f32,
+    max_moon_orbit_radius: f32,
+    moons: Vec<Moon>,
+    surface_temperature: f32,
+    habitability: HabitabilityClass,
+    #[serde(default)]
+    ring: Option<Ring>,
+}
+
+impl Planet {
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn designation(host: &SolarSystem, index: u32) -> String {
+        // Follows the common exoplanet convention: star name + a lowercase
+        // letter, starting at 'b' since 'a' is implicitly the star itself.
+        let letter = (b'b' + index as u8) as char;
+        format!("{} {}", host.get_name(), letter)
+    }
+
+    /// Maximum orbit radius (AU) a satellite of this planet can safely
+    /// occupy, derived from the planet's Hill sphere.
+    pub fn get_max_moon_orbit_radius(&self) -> f32 {
+        self.max_moon_orbit_radius
+    }
+
+    /// Equilibrium surface temperature, in Kelvin.
+    pub fn get_surface_temperature(&self) -> f32 {
+        self.surface_temperature
+    }
+
+    pub fn get_habitability_class(&self) -> HabitabilityClass {
+        self.habitability
+    }
+
+    pub fn get_ring(&self) -> Option<Ring> {
+        self.ring
+    }
+
+    /// Generates a planet orbiting `host`, drawing every random quantity
+    /// from `rng` so that generation can be made deterministic by seeding it.
+    pub(crate) fn generate_with_rng(host: SolarSystem, rng: &mut impl Rng) -> Self {
+        let index = host.get_n_planets() as u32;
+        let semi_major_axis = host.get_nth_orbit_radius(index);
+
+        // Most orbits are near-circular, with a long tail of more eccentric
+        // ones, so a half-normal distribution keeps things subtle but varied.
+        let eccentricity: f32 = rand_distr::Normal::new(0.0_f32, 0.06_f32)
+            .unwrap()
+            .sample(rng)
+            .abs()
+            .min(0.9);
+
+        let inclination = rand_distr::Normal::new(0.0, 3.0_f32.to_radians())
+            .unwrap()
+            .sample(rng);
+
+        let mass: f32 = rand_distr::LogNormal::new(-7.0, 1.5)
+            .unwrap()
+            .sample(rng);
+        let radius = mass.powf(0.5);
+
+        let orbit_period = orbit_dynamics::calculate_orbital_period(semi_major_axis, host.get_star_mass());
+        let angular_speed = orbit_dynamics::calculate_angular_speed(orbit_period);
+
+        // At t = 0 every planet starts at periapsis (E = 0, so ν = 0).
+        let current_radius = semi_major_axis * (1.0 - eccentricity);
+
+        let hill_radius = astrophysics::calculate_hill_radius(
+            semi_major_axis,
+            eccentricity,
+            mass,
+            host.get_star_mass(),
+        );
+        let max_moon_orbit_radius = hill_radius * MAX_MOON_ORBIT_FRACTION;
+
+        // Bond albedo of rocky-to-icy bodies in the solar system ranges
+        // roughly from 0.1 (Mercury) to 0.9 (Enceladus), so sample uniformly
+        // across that span.
+        let albedo: f32 = rng.gen_range(0.1..0.9);
+        let star = host.get_star();
+        let surface_temperature = astrophysics::calculate_equilibrium_temperature(
+            star.get_surface_temperature(),
+            star.get_radius(),
+            semi_major_axis,
+            albedo,
+        );
+        let habitability = HabitabilityClass::from_temperature(surface_temperature);
+
+        // Only gas giants have enough mass (and a deep enough gravity well)
+        // to hold on to a ring system.
+        let ring = if mass >= GAS_GIANT_MASS_THRESHOLD && rng.gen_bool(RING_PROBABILITY) {
+            let inner_radius = radius * rng.gen_range(1.2..2.0);
+            let outer_radius = inner_radius + radius * rng.gen_range(0.5..2.0);
+            Some(Ring { inner_radius, outer_radius })
+        } else {
+            None
+        };
+
+        let name = Self::designation(&host, index);
+
+        let mut planet = Self {
+            id: Uuid::from_bytes(rng.gen()),
+            name,
+            mass,
+            radius,
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            orbit_period,
+            angular_speed,
+            orbit_position: 0.0,
+            current_radius,
+            time_elapsed: 0.0,
+            max_moon_orbit_radius,
+            moons: vec![],
+            surface_temperature,
+            habitability,
+            ring,
+        };
+
+        let n_moons: i32 = rand_distr::Normal::new(1.0_f32, 1.2_f32)
+            .unwrap()
+            .sample(rng)
+            .round() as i32;
+
+        for _ in 0..n_moons.clamp(0, 5) {
+            let moon = Moon::generate_with_rng(planet.clone(), rng);
+            planet.moons.push(moon);
+        }
+
+        planet
+    }
+}
+
+impl CelestialBody for Planet {
+    type HostType = SolarSystem;
+
+    fn get_type(&self) -> CelestialBodyType {
+        if self.mass >= GAS_GIANT_MASS_THRESHOLD {
+            CelestialBodyType::GasGiant
+        } else {
+            CelestialBodyType::Planet
+        }
+    }
+
+    fn get_mass(&self) -> f32 {
+        self.mass
+    }
+
+    fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    fn generate(host: SolarSystem) -> Self {
+        Self::generate_with_rng(host, &mut rand::thread_rng())
+    }
+}
+
+impl CanOrbit for Planet {
+    type HostType = SolarSystem;
+
+    fn get_orbit_radius(&self) -> f32 {
+        self.current_radius
+    }
+
+    fn get_orbit_period(&self) -> f32 {
+        self.orbit_period
+    }
+
+    fn get_orbit_position(&self) -> f32 {
+        self.orbit_position
+    }
+
+    fn get_angular_speed(&self) -> f32 {
+        self.angular_speed
+    }
+
+    fn get_eccentricity(&self) -> f32 {
+        self.eccentricity
+    }
+
+    fn get_inclination(&self) -> f32 {
+        self.inclination
+    }
+
+    fn get_semi_major_axis(&self) -> f32 {
+        self.semi_major_axis
+    }
+
+    fn update_orbit_position(&mut self) {
+        self.time_elapsed += ORBIT_TIME_STEP;
+
+        let mean_anomaly = orbit_dynamics::calculate_mean_anomaly(self.time_elapsed, self.orbit_period);
+        let eccentric_anomaly = orbit_dynamics::solve_kepler_equation(mean_anomaly, self.eccentricity);
+
+        self.orbit_position = orbit_dynamics::calculate_true_anomaly(eccentric_anomaly, self.eccentricity);
+        self.current_radius =
+            orbit_dynamics::calculate_orbital_radius(self.semi_major_axis, self.eccentricity, eccentric_anomaly);
+    }
+}
+
+impl Orbitable for Planet {
+    type SatelliteType = Moon;
+
+    fn get_satellites(&self) -> Vec<Moon> {
+        self.moons.clone()
+    }
+
+    fn update_orbits(&mut self) {
+        self.moons.iter_mut().for_each(|m| m.update_orbit_position());
+    }
+}
+
+impl Displayable for Planet {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        let mut properties = vec![
+            vec!["Mass".to_string(), format!("{:.4} M\u{2609}", self.mass)],
+            vec!["Radius".to_string(), format!("{:.4} R\u{2609}", self.radius)],
+            vec!["Orbit radius".to_string(), format!("{:.3} AU", self.semi_major_axis)],
+            vec!["Eccentricity".to_string(), format!("{:.3}", self.eccentricity)],
+            vec!["Surface temperature".to_string(), format!("{:.0} K", self.surface_temperature)],
+            vec!["Habitability".to_string(), format!("{:?}", self.habitability)],
+        ];
+
+        if let Some(ring) = &self.ring {
+            properties.push(vec![
+                "Ring".to_string(),
+                format!("{:.4}-{:.4} R\u{2609}", ring.get_inner_radius(), ring.get_outer_radius()),
+            ]);
+        }
+
+        properties
+    }
+}
@@ -1,18 +1,34 @@
 use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rand_distr;
-use crate::game::celestial_bodies::{CelestialBody, CelestialBodyType};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::game::celestial_bodies::asteroid_belt::AsteroidBelt;
+use crate::game::celestial_bodies::{CanOrbit, CelestialBody, CelestialBodyType, Displayable, Orbitable};
 use crate::game::celestial_bodies::planet::Planet;
 use crate::game::celestial_bodies::star::Star;
 use crate::game::helpers::{astrophysics, orbit_dynamics};
 
-#[derive(Clone)]
+/// Chance that the gap between any two adjacent planet orbits hosts an
+/// asteroid belt.
+const BELT_PROBABILITY: f64 = 0.3;
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SolarSystem {
+    id: Uuid,
     star: Star,
     planets: Vec<Planet>,
+    #[serde(default)]
+    belts: Vec<AsteroidBelt>,
     spacing_factor: f32,
 }
 
 impl SolarSystem {
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
     pub fn get_n_planets(&self) -> usize {
         self.planets.len()
     }
@@ -22,6 +38,8 @@ impl SolarSystem {
 
     pub fn get_planets(&self) -> Vec<Planet> { self.planets.clone() }
 
+    pub fn get_belts(&self) -> Vec<AsteroidBelt> { self.belts.clone() }
+
     pub fn get_inner_limit(&self) -> f32 {
         astrophysics::calculate_system_inner_limit_from_star_radius_and_density(
             self.star.get_radius(),
@@ -32,25 +50,143 @@ impl SolarSystem {
         )
     }
 
+    /// Inner/outer orbit radii (AU) of the circumstellar habitable zone,
+    /// i.e. the band where a body with a reference Bond albedo would have
+    /// an equilibrium temperature in the liquid-water range.
+    pub fn get_habitable_zone(&self) -> (f32, f32) {
+        const REFERENCE_ALBEDO: f32 = 0.3;
+
+        let inner = astrophysics::calculate_orbit_radius_for_equilibrium_temperature(
+            self.star.get_surface_temperature(),
+            self.star.get_radius(),
+            astrophysics::HABITABLE_MAX_TEMPERATURE,
+            REFERENCE_ALBEDO,
+        );
+        let outer = astrophysics::calculate_orbit_radius_for_equilibrium_temperature(
+            self.star.get_surface_temperature(),
+            self.star.get_radius(),
+            astrophysics::HABITABLE_MIN_TEMPERATURE,
+            REFERENCE_ALBEDO,
+        );
+
+        (inner, outer)
+    }
+
     pub  fn get_nth_orbit_radius(&self, n: u32) -> f32 {
-        if self.planets.len() > 0 {
-            astrophysics::calculate_nth_orbit(
-                self.planets[0].get_orbit_radius(),
-                self.spacing_factor,
-                n,
-            )
+        let first_orbit_radius = if self.planets.len() > 0 {
+            self.planets[0].get_semi_major_axis()
         } else {
-            0.0
+            // Before any planet exists (i.e. while generating the first
+            // one), anchor the geometric spacing law to the closest safe
+            // orbit around the star instead of falling through to 0 AU.
+            self.get_inner_limit()
+        };
+
+        astrophysics::calculate_nth_orbit(first_orbit_radius, self.spacing_factor, n)
+    }
+
+    /// Generates a system deterministically from `seed`: the same seed
+    /// always produces the same star, planets and orbits, enabling
+    /// save/share-by-seed.
+    pub fn generate_with_seed(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self::generate_with_rng(&mut rng)
+    }
+
+    fn generate_with_rng(rng: &mut impl Rng) -> Self {
+        let spacing_factor = rand_distr::Normal::new(
+            0.4,
+            0.2
+        ).unwrap().sample(rng);
+
+        let mut system = Self {
+            id: Uuid::from_bytes(rng.gen()),
+            star: Star::generate_with_rng(rng),
+            planets: vec![],
+            belts: vec![],
+            spacing_factor,
+        };
+
+        let n_planets: i32 = rand_distr::Normal::new(
+            5.0,
+            1.0
+        ).unwrap().sample(rng) as i32;
+
+        for _ in 0..n_planets {
+            system.planets.push(Planet::generate_with_rng(system.clone(), rng));
         }
+
+        for n in 0..system.planets.len().saturating_sub(1) as u32 {
+            if rng.gen_bool(BELT_PROBABILITY) {
+                let inner_radius = system.get_nth_orbit_radius(n);
+                let outer_radius = system.get_nth_orbit_radius(n + 1);
+                system.belts.push(AsteroidBelt::generate_with_rng(inner_radius, outer_radius, rng));
+            }
+        }
+
+        system
+    }
+
+    /// Saves the system to `path` as pretty-printed JSON, capturing every
+    /// orbital element, mass, radius and name so it can be loaded back
+    /// without re-running the generator.
+    pub fn save_to_json(&self, path: &str) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("SolarSystem is always serializable");
+        std::fs::write(path, json)
+    }
+
+    /// Loads a system previously written by `save_to_json`.
+    pub fn load_from_json(path: &str) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
     }
 }
 
-impl CelestialBody for SolarSystem {
-    type HostType = ();
+impl Displayable for SolarSystem {
     fn get_name(&self) -> String {
         self.star.get_name()
     }
 
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        self.belts
+            .iter()
+            .enumerate()
+            .map(|(i, belt)| {
+                let composition = belt.get_composition();
+                vec![
+                    format!("Belt {}", i + 1),
+                    format!(
+                        "{:.3}-{:.3} AU (mineral {:.0}%, metal {:.0}%, ice {:.0}%)",
+                        belt.get_inner_radius(),
+                        belt.get_outer_radius(),
+                        composition.mineral * 100.0,
+                        composition.metal * 100.0,
+                        composition.ice * 100.0,
+                    ),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Orbitable for SolarSystem {
+    type SatelliteType = Planet;
+
+    fn get_satellites(&self) -> Vec<Planet> {
+        self.planets.clone()
+    }
+
+    fn update_orbits(&mut self) {
+        self.planets.iter_mut().for_each(|p| {
+            p.update_orbit_position();
+            p.update_orbits();
+        });
+    }
+}
+
+impl CelestialBody for SolarSystem {
+    type HostType = ();
+
     fn get_type(&self) -> CelestialBodyType {
         CelestialBodyType::SolarSystem
     }
@@ -67,29 +203,7 @@ impl CelestialBody for SolarSystem {
         self.planets.last().unwrap().get_orbit_radius()
     }
 
-    fn generate(host: ()) -> Self {
-        let mut rng = rand::thread_rng();
-
-        let spacing_factor = rand_distr::Normal::new(
-            0.4,
-            0.2
-        ).unwrap().sample(&mut rng);
-
-        let mut system = Self {
-            star: Star::generate(()),
-            planets: vec![],
-            spacing_factor,
-        };
-
-        let n_planets: i32 = rand_distr::Normal::new(
-            5.0,
-            1.0
-        ).unwrap().sample(&mut rng) as i32;
-
-        for _ in 0..n_planets {
-            system.planets.push(Planet::generate(system.clone()));
-        }
-
-        system
+    fn generate(_host: ()) -> Self {
+        Self::generate_with_seed(rand::thread_rng().gen())
     }
 }
\ No newline at end of file
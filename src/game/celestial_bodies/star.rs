@@ -0,0 +1,191 @@
+use rand::distributions::Distribution;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_distr;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::celestial_bodies::constants::STAR_NAMES;
+use crate::game::celestial_bodies::{CelestialBody, CelestialBodyType, Displayable};
+
+/// The Morgan-Keenan spectral class of a star, in decreasing order of mass
+/// and surface temperature. Each class carries the min/max ranges that
+/// `Star::generate` draws its mass, radius and temperature from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SpectralClass {
+    O,
+    B,
+    A,
+    F,
+    G,
+    K,
+    M,
+}
+
+impl SpectralClass {
+    /// Picks the class a star of the given mass (solar masses) belongs to.
+    fn from_mass(mass: f32) -> Self {
+        match mass {
+            m if m >= 16.0 => SpectralClass::O,
+            m if m >= 2.1 => SpectralClass::B,
+            m if m >= 1.4 => SpectralClass::A,
+            m if m >= 1.04 => SpectralClass::F,
+            m if m >= 0.8 => SpectralClass::G,
+            m if m >= 0.45 => SpectralClass::K,
+            _ => SpectralClass::M,
+        }
+    }
+
+    /// Mass range for the class, in solar masses.
+    fn mass_range(&self) -> (f32, f32) {
+        match self {
+            SpectralClass::O => (16.0, 90.0),
+            SpectralClass::B => (2.1, 16.0),
+            SpectralClass::A => (1.4, 2.1),
+            SpectralClass::F => (1.04, 1.4),
+            SpectralClass::G => (0.8, 1.04),
+            SpectralClass::K => (0.45, 0.8),
+            SpectralClass::M => (0.08, 0.45),
+        }
+    }
+
+    /// Radius range for the class, in solar radii.
+    fn radius_range(&self) -> (f32, f32) {
+        match self {
+            SpectralClass::O => (6.6, 20.0),
+            SpectralClass::B => (1.8, 6.6),
+            SpectralClass::A => (1.4, 1.8),
+            SpectralClass::F => (1.15, 1.4),
+            SpectralClass::G => (0.96, 1.15),
+            SpectralClass::K => (0.7, 0.96),
+            SpectralClass::M => (0.1, 0.7),
+        }
+    }
+
+    /// Effective surface temperature range for the class, in Kelvin.
+    fn temperature_range(&self) -> (f32, f32) {
+        match self {
+            SpectralClass::O => (30_000.0, 60_000.0),
+            SpectralClass::B => (10_000.0, 30_000.0),
+            SpectralClass::A => (7_500.0, 10_000.0),
+            SpectralClass::F => (6_000.0, 7_500.0),
+            SpectralClass::G => (5_200.0, 6_000.0),
+            SpectralClass::K => (3_700.0, 5_200.0),
+            SpectralClass::M => (2_400.0, 3_700.0),
+        }
+    }
+
+    /// Conventional color used to render the class in the menu.
+    fn menu_color(&self) -> ratatui::style::Color {
+        use ratatui::style::Color;
+        match self {
+            SpectralClass::O => Color::Blue,
+            SpectralClass::B => Color::LightBlue,
+            SpectralClass::A => Color::White,
+            SpectralClass::F => Color::LightYellow,
+            SpectralClass::G => Color::Yellow,
+            SpectralClass::K => Color::Rgb(255, 165, 0),
+            SpectralClass::M => Color::Red,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Star {
+    id: Uuid,
+    name: String,
+    mass: f32,
+    radius: f32,
+    spectral_class: SpectralClass,
+    surface_temperature: f32,
+}
+
+impl Star {
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_spectral_class(&self) -> SpectralClass {
+        self.spectral_class
+    }
+
+    pub fn get_surface_temperature(&self) -> f32 {
+        self.surface_temperature
+    }
+
+    /// Generates a star, drawing every random quantity from `rng` so that
+    /// generation can be made deterministic by seeding it.
+    pub(crate) fn generate_with_rng(rng: &mut impl Rng) -> Self {
+        // Real stellar populations are dominated by low-mass M dwarfs, so we
+        // draw from a distribution skewed heavily toward the low end before
+        // classifying, rather than sampling the class itself uniformly.
+        let sampled_mass: f32 = (0.08 + rand_distr::Exp::new(2.5_f32).unwrap().sample(rng)).min(90.0);
+        let spectral_class = SpectralClass::from_mass(sampled_mass);
+
+        let (mass_min, mass_max) = spectral_class.mass_range();
+        let mass = rng.gen_range(mass_min..mass_max);
+
+        let (radius_min, radius_max) = spectral_class.radius_range();
+        let radius = rng.gen_range(radius_min..radius_max);
+
+        let (temp_min, temp_max) = spectral_class.temperature_range();
+        let surface_temperature = rng.gen_range(temp_min..temp_max);
+
+        let name = STAR_NAMES
+            .choose(rng)
+            .cloned()
+            .unwrap_or_else(|| "Unnamed Star".to_string());
+
+        Self {
+            id: Uuid::from_bytes(rng.gen()),
+            name,
+            mass,
+            radius,
+            spectral_class,
+            surface_temperature,
+        }
+    }
+}
+
+impl CelestialBody for Star {
+    type HostType = ();
+
+    fn get_type(&self) -> CelestialBodyType {
+        CelestialBodyType::Star
+    }
+
+    fn get_mass(&self) -> f32 {
+        self.mass
+    }
+
+    fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    fn generate(_host: ()) -> Self {
+        Self::generate_with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl Displayable for Star {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec!["Spectral class".to_string(), format!("{:?}", self.spectral_class)],
+            vec!["Mass".to_string(), format!("{:.2} M\u{2609}", self.mass)],
+            vec!["Radius".to_string(), format!("{:.2} R\u{2609}", self.radius)],
+            vec!["Surface temperature".to_string(), format!("{:.0} K", self.surface_temperature)],
+        ]
+    }
+
+    fn get_menu_color(&self) -> ratatui::style::Color {
+        self.spectral_class.menu_color()
+    }
+}
@@ -0,0 +1,73 @@
+//! Helper formulas for generating physically-plausible systems.
+//!
+//! Units follow the usual astronomical convention used throughout the
+//! generator: mass in solar masses, radius in solar radii, orbit radius in AU.
+
+/// One solar radius expressed in AU.
+pub const SOLAR_RADIUS_IN_AU: f32 = 0.00465047;
+
+/// Lower bound (Kelvin) of the liquid-water habitable temperature band.
+pub const HABITABLE_MIN_TEMPERATURE: f32 = 273.0;
+
+/// Upper bound (Kelvin) of the liquid-water habitable temperature band.
+pub const HABITABLE_MAX_TEMPERATURE: f32 = 373.0;
+
+/// Rough factor relating a star's radius/density to the closest distance a
+/// planet can form at without being tidally disrupted or fried.
+const INNER_LIMIT_FACTOR: f32 = 20.0;
+
+/// Average density of a body from its mass and radius, in solar-mass per
+/// solar-radius-cubed units.
+pub fn calculate_density_from_mass_and_radius(mass: f32, radius: f32) -> f32 {
+    mass / radius.powi(3)
+}
+
+/// Closest orbit radius (AU) a planet can safely occupy around a star,
+/// derived from the star's radius and density.
+pub fn calculate_system_inner_limit_from_star_radius_and_density(
+    radius: f32,
+    density: f32,
+) -> f32 {
+    radius * SOLAR_RADIUS_IN_AU * density.sqrt() * INNER_LIMIT_FACTOR
+}
+
+/// Radius (AU) of the `n`th orbit, counting outward from the first planet's
+/// orbit, following a Titius-Bode-style geometric spacing law.
+pub fn calculate_nth_orbit(first_orbit_radius: f32, spacing_factor: f32, n: u32) -> f32 {
+    first_orbit_radius * (1.0 + spacing_factor).powi(n as i32)
+}
+
+/// Hill radius (AU): the distance from `body_mass` within which its gravity
+/// dominates over `host_mass`'s, beyond which a satellite's orbit becomes
+/// dynamically unstable.
+pub fn calculate_hill_radius(semi_major_axis: f32, eccentricity: f32, body_mass: f32, host_mass: f32) -> f32 {
+    semi_major_axis * (1.0 - eccentricity) * (body_mass / (3.0 * host_mass)).cbrt()
+}
+
+/// Equilibrium surface temperature (Kelvin) of a body orbiting at
+/// `orbit_radius` (AU) around a star of effective temperature
+/// `star_temperature` (Kelvin) and radius `star_radius` (solar radii),
+/// reflecting a fraction `bond_albedo` of incident light.
+pub fn calculate_equilibrium_temperature(
+    star_temperature: f32,
+    star_radius: f32,
+    orbit_radius: f32,
+    bond_albedo: f32,
+) -> f32 {
+    let star_radius_au = star_radius * SOLAR_RADIUS_IN_AU;
+    star_temperature * (star_radius_au / (2.0 * orbit_radius)).sqrt() * (1.0 - bond_albedo).powf(0.25)
+}
+
+/// Orbit radius (AU) at which a body around the given star would settle at
+/// `target_temperature` (Kelvin), for a body with the given `bond_albedo`.
+/// This is `calculate_equilibrium_temperature` solved for `orbit_radius`.
+pub fn calculate_orbit_radius_for_equilibrium_temperature(
+    star_temperature: f32,
+    star_radius: f32,
+    target_temperature: f32,
+    bond_albedo: f32,
+) -> f32 {
+    let star_radius_au = star_radius * SOLAR_RADIUS_IN_AU;
+    let ratio = star_temperature * (1.0 - bond_albedo).powf(0.25) / target_temperature;
+    star_radius_au / 2.0 * ratio.powi(2)
+}
@@ -0,0 +1,2 @@
+pub mod astrophysics;
+pub mod orbit_dynamics;
@@ -0,0 +1,46 @@
+//! Helpers for propagating a body's position along its orbit over time.
+
+use std::f32::consts::PI;
+
+/// Number of Newton-Raphson steps used to solve Kepler's equation. Five
+/// iterations converge well past single-precision accuracy for `e < 0.9`.
+const KEPLER_SOLVER_STEPS: u32 = 5;
+
+/// Orbital period (years) from Kepler's third law, given the semi-major
+/// axis (AU) and the host's mass (solar masses).
+pub fn calculate_orbital_period(semi_major_axis: f32, host_mass: f32) -> f32 {
+    (semi_major_axis.powi(3) / host_mass).sqrt()
+}
+
+/// Mean angular speed (rad/unit time) implied by an orbital period.
+pub fn calculate_angular_speed(orbit_period: f32) -> f32 {
+    2.0 * PI / orbit_period
+}
+
+/// Mean anomaly `M` at elapsed time `t`, wrapped into `[0, 2pi)`.
+pub fn calculate_mean_anomaly(elapsed_time: f32, orbit_period: f32) -> f32 {
+    (2.0 * PI * elapsed_time / orbit_period).rem_euclid(2.0 * PI)
+}
+
+/// Solves Kepler's equation `E - e*sin(E) = M` for the eccentric anomaly `E`
+/// by Newton-Raphson, starting from `E0 = M`.
+pub fn solve_kepler_equation(mean_anomaly: f32, eccentricity: f32) -> f32 {
+    let mut e = mean_anomaly;
+    for _ in 0..KEPLER_SOLVER_STEPS {
+        e -= (e - eccentricity * e.sin() - mean_anomaly) / (1.0 - eccentricity * e.cos());
+    }
+    e
+}
+
+/// True anomaly `ν` from the eccentric anomaly `E` and eccentricity `e`.
+pub fn calculate_true_anomaly(eccentric_anomaly: f32, eccentricity: f32) -> f32 {
+    2.0 * f32::atan2(
+        (1.0 + eccentricity).sqrt() * (eccentric_anomaly / 2.0).sin(),
+        (1.0 - eccentricity).sqrt() * (eccentric_anomaly / 2.0).cos(),
+    )
+}
+
+/// Orbital radius `r = a*(1 - e*cos(E))` at eccentric anomaly `E`.
+pub fn calculate_orbital_radius(semi_major_axis: f32, eccentricity: f32, eccentric_anomaly: f32) -> f32 {
+    semi_major_axis * (1.0 - eccentricity * eccentric_anomaly.cos())
+}
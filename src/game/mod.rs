@@ -0,0 +1,2 @@
+pub mod celestial_bodies;
+pub mod helpers;
@@ -0,0 +1,11 @@
+mod game;
+
+fn main() {
+    use game::celestial_bodies::CelestialBody;
+    let system = game::celestial_bodies::solar_system::SolarSystem::generate(());
+
+    println!("Generated system around {}", {
+        use game::celestial_bodies::Displayable;
+        system.get_name()
+    });
+}